@@ -1,5 +1,6 @@
 use bevy::input::mouse::{MouseMotion, MouseWheel};
 use bevy::prelude::*;
+use bevy::render::camera::ScalingMode;
 use bevy::window::PrimaryWindow;
 use bevy_inspector_egui::bevy_egui::egui;
 use bevy_egui::EguiContexts;
@@ -33,12 +34,37 @@ enum GizmoSpace {
 }
 
 /// Tags an entity as capable of panning and orbiting.
+///
+/// `pan_orbit_camera` also requires a [`PanOrbitSettings`] and a
+/// [`ProjectionMode`] on the same entity; spawn [`PanOrbitCameraBundle`]
+/// rather than this component alone, or the camera will silently stop
+/// being driven by mouse/keyboard input.
 #[derive(Component)]
 pub struct PanOrbitCamera {
     /// The "focus point" to orbit around. It is automatically updated when panning the camera
     pub focus: Vec3,
     pub radius: f32,
-    pub upside_down: bool,
+    /// Accumulated rotation about the global Y axis, in radians.
+    pub yaw: f32,
+    /// Accumulated rotation about the local X axis, in radians. Clamped just
+    /// short of ±90° so the camera can never tumble over the pole.
+    pub pitch: f32,
+    /// Pivot point cached for the duration of the current orbit drag. Set by
+    /// raycasting under the cursor when the drag begins, and cleared when it ends.
+    /// Falls back to `focus` while `None`.
+    pub orbit_center: Option<Vec3>,
+    /// Set once `yaw`/`pitch` have been seeded from the entity's spawn
+    /// `Transform`, so the authored viewpoint isn't overwritten by the
+    /// `0.0`/`0.0` defaults on the first orbit frame.
+    initialized: bool,
+    /// Smoothed per-frame orbit deltas (pixels-equivalent), carried across
+    /// frames so motion keeps gliding and decaying after the mouse stops.
+    yaw_velocity: f32,
+    pitch_velocity: f32,
+    /// Smoothed per-frame pan delta, same inertia treatment as the orbit velocities.
+    pan_velocity: Vec2,
+    /// Smoothed per-frame scroll delta, same inertia treatment as the orbit velocities.
+    zoom_velocity: f32,
 }
 
 impl Default for PanOrbitCamera {
@@ -46,11 +72,110 @@ impl Default for PanOrbitCamera {
         PanOrbitCamera {
             focus: Vec3::ZERO,
             radius: 5.0,
-            upside_down: false,
+            yaw: 0.0,
+            pitch: 0.0,
+            orbit_center: None,
+            initialized: false,
+            yaw_velocity: 0.0,
+            pitch_velocity: 0.0,
+            pan_velocity: Vec2::ZERO,
+            zoom_velocity: 0.0,
         }
     }
 }
 
+/// Input bindings and sensitivities for a [`PanOrbitCamera`].
+///
+/// Spawn this alongside `PanOrbitCamera` to customize the mouse/keyboard
+/// mapping, e.g. to emulate Blender, Maya, or CAD conventions. The default
+/// bindings and sensitivities reproduce the camera's original hard-coded
+/// values; smoothing is on by default (see `smoothing`), so motion eases in
+/// and glides out rather than following the mouse 1:1.
+#[derive(Component)]
+pub struct PanOrbitSettings {
+    /// Multiplier on pan speed. The base pan is scaled by `fov` and the focus
+    /// distance (`radius`) in perspective, and by `scale` in orthographic.
+    pub pan_sensitivity: f32,
+    /// Multiplier on orbit speed. At `1.0`, dragging across the full window
+    /// width turns the camera a full circle, and the full height half a circle.
+    pub orbit_sensitivity: f32,
+    /// Fraction of `radius` (perspective) or `scale` (orthographic) removed
+    /// per scroll unit; `fov` is not changed by scrolling.
+    pub zoom_sensitivity: f32,
+    /// Mouse button that starts an orbit drag. `None` disables orbiting.
+    pub orbit_button: Option<MouseButton>,
+    /// Mouse button that starts a pan drag. `None` disables panning.
+    pub pan_button: Option<MouseButton>,
+    /// Held with `pan_button` to pan instead of orbit. `None` disables this modifier.
+    pub pan_key_left: Option<KeyCode>,
+    /// Held with `pan_button` to pan instead of orbit. `None` disables this modifier.
+    pub pan_key_right: Option<KeyCode>,
+    /// Key that toggles between [`ProjectionMode::Perspective`] and
+    /// [`ProjectionMode::Orthographic`]. `None` disables the shortcut.
+    pub projection_toggle_key: Option<KeyCode>,
+    /// Lower/upper bound, in radians, for `Projection::Perspective`'s `fov`.
+    pub min_fov: f32,
+    pub max_fov: f32,
+    /// Lower/upper bound for `Projection::Orthographic`'s `scale`.
+    pub min_scale: f32,
+    pub max_scale: f32,
+    /// Exponential smoothing rate (per second) used to ease orbit/pan/zoom
+    /// toward their raw input targets and to let residual motion glide to a
+    /// stop once input ends. Higher is snappier; `f32::INFINITY` disables smoothing.
+    pub smoothing: f32,
+    /// Residual velocity below which inertia is snapped to exactly zero, so
+    /// the camera settles instead of drifting forever.
+    pub velocity_threshold: f32,
+}
+
+impl Default for PanOrbitSettings {
+    fn default() -> Self {
+        PanOrbitSettings {
+            pan_sensitivity: 1.0,
+            orbit_sensitivity: 1.0,
+            zoom_sensitivity: 0.002,
+            orbit_button: Some(MouseButton::Middle),
+            pan_button: Some(MouseButton::Middle),
+            pan_key_left: Some(KeyCode::LShift),
+            pan_key_right: Some(KeyCode::RShift),
+            projection_toggle_key: Some(KeyCode::Numpad5),
+            min_fov: 1.0_f32.to_radians(),
+            max_fov: 170.0_f32.to_radians(),
+            min_scale: 0.01,
+            max_scale: 1000.0,
+            smoothing: 12.0,
+            velocity_threshold: 1e-4,
+        }
+    }
+}
+
+/// Whether a [`PanOrbitCamera`] renders through a perspective or orthographic
+/// lens. Toggled by [`PanOrbitSettings::projection_toggle_key`] or the
+/// "Transform Gizmo" egui window; useful for the top/front/side technical
+/// views common in scene editors.
+#[derive(Component, Debug, PartialEq, Copy, Clone)]
+pub enum ProjectionMode {
+    Perspective,
+    Orthographic,
+}
+
+impl Default for ProjectionMode {
+    fn default() -> Self {
+        ProjectionMode::Perspective
+    }
+}
+
+/// Spawn this alongside a `Camera3dBundle` to get an interactive, free-flying
+/// camera. Bundles [`PanOrbitCamera`] with the [`PanOrbitSettings`] and
+/// [`ProjectionMode`] that `pan_orbit_camera` also requires, so the camera
+/// can't silently end up missing one of them.
+#[derive(Bundle, Default)]
+pub struct PanOrbitCameraBundle {
+    pub pan_orbit: PanOrbitCamera,
+    pub settings: PanOrbitSettings,
+    pub projection_mode: ProjectionMode,
+}
+
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugin(bevy_transform_gizmo::TransformGizmoPlugin)
@@ -58,15 +183,110 @@ impl Plugin for CameraPlugin {
                 (pan_orbit_camera, center_selection)
                     .chain()
                     .in_base_set(CoreSet::Update)
+                    .run_if(user_camera_active)
+                    .after(cycle_active_camera)
                     //.run_if(plugin_enabled),
             )
             .add_systems(
                 (ui_system, update_gizmo_space).chain()
             )
+            .add_systems(
+                (collect_scene_cameras, cycle_active_camera)
+                    .chain()
+                    .in_base_set(CoreSet::Update)
+            )
             .insert_resource(CameraData {
                 transform_orientation: GizmoSpace::Global,
                 ui_show_transform_or_scale: TransformOrScale::Transform,
-            });
+            })
+            .init_resource::<SceneCameraCycle>();
+    }
+}
+
+/// Tracks camera entities loaded from glTF scenes so they can be cycled
+/// through with the interactive [`PanOrbitCamera`] (which always stays
+/// available as the "user" camera, at index 0).
+#[derive(Resource, Default)]
+struct SceneCameraCycle {
+    gltf_cameras: Vec<Entity>,
+    active: usize,
+}
+
+/// Picks up camera entities spawned by a loaded glTF scene (glTF files can
+/// embed cameras) and disables them until the user cycles to them, so only
+/// the interactive [`PanOrbitCamera`] renders by default.
+///
+/// Cameras spawned under a `SceneBundle`/`GltfBundle` are parented to the
+/// scene root, unlike the `PanOrbitCamera` or any runtime-added UI/overlay
+/// camera, so `With<Parent>` is used to tell scene cameras apart from those.
+fn collect_scene_cameras(
+    mut cycle: ResMut<SceneCameraCycle>,
+    mut new_cameras: Query<(Entity, &mut Camera), (Added<Camera>, Without<PanOrbitCamera>, With<Parent>)>,
+    mut user_camera: Query<&mut Camera, With<PanOrbitCamera>>,
+    mut removed_cameras: RemovedComponents<Camera>,
+) {
+    for (entity, mut camera) in &mut new_cameras {
+        camera.is_active = false;
+        cycle.gltf_cameras.push(entity);
+    }
+
+    // drop entities despawned (e.g. by a scene reload) so `cycle.active` can
+    // never land on a dead entity and leave no camera active
+    for entity in removed_cameras.iter() {
+        let Some(index) = cycle.gltf_cameras.iter().position(|&gltf_camera| gltf_camera == entity) else {
+            continue;
+        };
+        cycle.gltf_cameras.remove(index);
+        if cycle.active == index + 1 {
+            // the camera on screen is gone: fall back to the user camera
+            cycle.active = 0;
+            if let Ok(mut camera) = user_camera.get_single_mut() {
+                camera.is_active = true;
+            }
+        } else if cycle.active > index + 1 {
+            cycle.active -= 1;
+        }
+    }
+}
+
+/// Whether the interactive [`PanOrbitCamera`] is the one currently being
+/// shown, as opposed to a glTF-authored camera selected via [`cycle_active_camera`].
+/// Gates `pan_orbit_camera`/`center_selection` so mouse input doesn't keep
+/// driving a hidden camera while an authored viewpoint is on screen.
+fn user_camera_active(cycle: Res<SceneCameraCycle>) -> bool {
+    cycle.active == 0
+}
+
+/// Cycles the active camera between the interactive [`PanOrbitCamera`] and
+/// any cameras authored in a loaded glTF scene, the way glTF sample viewers do.
+fn cycle_active_camera(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut cycle: ResMut<SceneCameraCycle>,
+    user_camera: Query<Entity, With<PanOrbitCamera>>,
+    mut cameras: Query<&mut Camera>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::C) || cycle.gltf_cameras.is_empty() {
+        return;
+    }
+    let Ok(user_camera) = user_camera.get_single() else {
+        return;
+    };
+
+    let camera_count = cycle.gltf_cameras.len() + 1;
+    cycle.active = (cycle.active + 1) % camera_count;
+    let active_entity = if cycle.active == 0 {
+        user_camera
+    } else {
+        cycle.gltf_cameras[cycle.active - 1]
+    };
+
+    if let Ok(mut camera) = cameras.get_mut(user_camera) {
+        camera.is_active = active_entity == user_camera;
+    }
+    for &entity in &cycle.gltf_cameras {
+        if let Ok(mut camera) = cameras.get_mut(entity) {
+            camera.is_active = entity == active_entity;
+        }
     }
 }
 
@@ -130,9 +350,11 @@ fn ui_system(
     mut egui_context: EguiContexts,
     mut app_assets: ResMut<CameraData>,
     mut enabled_systems: ResMut<GizmoPartsEnabled>,
+    mut projection_mode: Query<&mut ProjectionMode, With<PanOrbitCamera>>,
 ) {
     let mut selected = app_assets.transform_orientation;
     let mut showing = app_assets.ui_show_transform_or_scale;
+    let mut camera_mode = projection_mode.get_single_mut().ok();
     egui::Window::new("Transform Gizmo").show(egui_context.ctx_mut(), |ui| {
         egui::ComboBox::from_label("Orientation")
             .selected_text(format!("{:?}", selected))
@@ -160,19 +382,76 @@ fn ui_system(
         }
         ui.checkbox(&mut enabled_systems.rotate, "Rotate");
         ui.checkbox(&mut enabled_systems.translate_planes, "Planes");
+        if let Some(camera_mode) = camera_mode.as_deref_mut() {
+            ui.separator();
+            egui::ComboBox::from_label("Projection")
+                .selected_text(format!("{:?}", camera_mode))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(camera_mode, ProjectionMode::Perspective, "Perspective");
+                    ui.selectable_value(camera_mode, ProjectionMode::Orthographic, "Orthographic");
+                });
+        }
     });
     app_assets.ui_show_transform_or_scale = showing;
     app_assets.transform_orientation = selected;
     // update_gizmo_space(selection, selected, gizmo, camera);
 }
 
+/// Rebuilds `projection` to match `mode` whenever they've drifted apart,
+/// e.g. right after the egui toggle or keyboard shortcut flips the mode.
+fn sync_projection(projection: &mut Projection, mode: ProjectionMode, radius: f32, settings: &PanOrbitSettings) {
+    // derive the other projection's zoom level from the current one so toggling
+    // back and forth round-trips the apparent view size instead of resetting to
+    // a default that may show nothing (e.g. a 1.0 ortho scale against a 5-unit scene)
+    let radius = radius.max(0.05);
+    match (mode, &*projection) {
+        (ProjectionMode::Perspective, Projection::Orthographic(orthographic)) => {
+            // `orthographic.scale` is a half-height under `FixedVertical`, the
+            // scaling mode we set below on the way in, so this is the inverse
+            // of the `scale` derivation in the other match arm
+            let fov = (2.0 * (orthographic.scale / radius).atan()).clamp(settings.min_fov, settings.max_fov);
+            *projection = Projection::Perspective(PerspectiveProjection {
+                fov,
+                near: orthographic.near,
+                far: orthographic.far,
+                ..Default::default()
+            });
+        }
+        (ProjectionMode::Orthographic, Projection::Perspective(perspective)) => {
+            // half-height of the view at the current focus distance, so the
+            // ortho view shows roughly what the perspective view was showing
+            let scale = (radius * (perspective.fov / 2.0).tan()).clamp(settings.min_scale, settings.max_scale);
+            *projection = Projection::Orthographic(OrthographicProjection {
+                scale,
+                // `scale` above is a world-space half-height; `FixedVertical(2.0)`
+                // is the scaling mode whose `scale` means exactly that (the
+                // default `WindowSize` scale is a pixels-per-unit multiplier and
+                // would combine with our `scale` to show almost nothing)
+                scaling_mode: ScalingMode::FixedVertical(2.0),
+                near: perspective.near,
+                far: perspective.far,
+                ..Default::default()
+            });
+        }
+        _ => {}
+    }
+}
+
 fn pan_orbit_camera(
     window: Query<&Window, With<PrimaryWindow>>,
     mut ev_motion: EventReader<MouseMotion>,
     mut ev_scroll: EventReader<MouseWheel>,
     input_mouse: Res<Input<MouseButton>>,
-    mut query: Query<(&mut PanOrbitCamera, &mut Transform, &Projection)>,
+    mut query: Query<(
+        &PanOrbitSettings,
+        &mut PanOrbitCamera,
+        &mut Transform,
+        &mut Projection,
+        &mut ProjectionMode,
+        Option<&bevy_mod_picking::PickingCamera>,
+    )>,
     keyboard_input: Res<Input<KeyCode>>,
+    time: Res<Time>,
 ) {
 
     let window = match window.get_single() {
@@ -185,85 +464,180 @@ fn pan_orbit_camera(
         }
     };
 
-    // change input mapping for orbit and panning here
-    let orbit_button = MouseButton::Middle;
-    let pan_button = MouseButton::Middle;
-    let pan_key_left = KeyCode::LShift;
-    let pan_key_right = KeyCode::RShift;
-
-    let mut pan = Vec2::ZERO;
-    let mut rotation_move = Vec2::ZERO;
-    let mut scroll = 0.0;
-    let mut orbit_button_changed = false;
+    // Mouse motion and scroll are summed once up front since EventReader::iter
+    // drains the reader; per-entity bindings then decide how to apply them.
+    let motion_delta: Vec2 = ev_motion.iter().map(|ev| ev.delta).sum();
+    let scroll: f32 = ev_scroll.iter().map(|ev| ev.y).sum();
 
-    if input_mouse.pressed(orbit_button)
-        && !(keyboard_input.pressed(pan_key_right) || keyboard_input.pressed(pan_key_left))
+    for (settings, mut pan_orbit, mut transform, mut projection, mut projection_mode, picking_camera) in
+        query.iter_mut()
     {
-        for ev in ev_motion.iter() {
-            rotation_move += ev.delta;
+        if !pan_orbit.initialized {
+            // seed yaw/pitch from the authored spawn transform so the first
+            // orbit frame recomposes the same orientation instead of snapping
+            // to yaw = pitch = 0.0
+            let (yaw, pitch, _roll) = transform.rotation.to_euler(EulerRot::YXZ);
+            pan_orbit.yaw = yaw;
+            pan_orbit.pitch = pitch;
+            pan_orbit.initialized = true;
         }
-    } else if input_mouse.pressed(pan_button)
-        && (keyboard_input.pressed(pan_key_right) || keyboard_input.pressed(pan_key_left))
-    {
-        // Pan only if we're not rotating at the moment
-        for ev in ev_motion.iter() {
-            pan += ev.delta;
+
+        if let Some(toggle_key) = settings.projection_toggle_key {
+            if keyboard_input.just_pressed(toggle_key) {
+                *projection_mode = match *projection_mode {
+                    ProjectionMode::Perspective => ProjectionMode::Orthographic,
+                    ProjectionMode::Orthographic => ProjectionMode::Perspective,
+                };
+            }
         }
-    }
-    for ev in ev_scroll.iter() {
-        scroll += ev.y;
-    }
-    if input_mouse.just_released(orbit_button) || input_mouse.just_pressed(orbit_button) {
-        orbit_button_changed = true;
-    }
+        sync_projection(&mut projection, *projection_mode, pan_orbit.radius, settings);
+
+        let pan_key_held = settings
+            .pan_key_left
+            .map_or(false, |key| keyboard_input.pressed(key))
+            || settings
+                .pan_key_right
+                .map_or(false, |key| keyboard_input.pressed(key));
 
-    for (mut pan_orbit, mut transform, projection) in query.iter_mut() {
-        if orbit_button_changed {
-            // only check for upside down when orbiting started or ended this frame
-            // if the camera is "upside" down, panning horizontally would be inverted, so invert the input to make it correct
-            let up = transform.rotation * Vec3::Y;
-            pan_orbit.upside_down = up.y <= 0.0;
+        let orbiting = settings
+            .orbit_button
+            .map_or(false, |button| input_mouse.pressed(button) && !pan_key_held);
+        let panning = settings
+            .pan_button
+            .map_or(false, |button| input_mouse.pressed(button) && pan_key_held);
+
+        let mut target_rotation_move = Vec2::ZERO;
+        let mut target_pan = Vec2::ZERO;
+        if orbiting {
+            target_rotation_move = motion_delta;
+        } else if panning {
+            target_pan = motion_delta;
+        }
+
+        // Ease the raw per-frame targets toward smoothed velocities, and keep
+        // applying those velocities after the target drops to zero so motion
+        // glides to a stop instead of snapping — until it decays below threshold.
+        let smoothing_t = if settings.smoothing.is_infinite() {
+            // -inf * 0.0 is NaN on a zero-length frame, so bypass the formula
+            1.0
+        } else {
+            1.0 - (-settings.smoothing * time.delta_seconds()).exp()
+        };
+        pan_orbit.yaw_velocity += (target_rotation_move.x - pan_orbit.yaw_velocity) * smoothing_t;
+        pan_orbit.pitch_velocity += (target_rotation_move.y - pan_orbit.pitch_velocity) * smoothing_t;
+        pan_orbit.pan_velocity += (target_pan - pan_orbit.pan_velocity) * smoothing_t;
+        pan_orbit.zoom_velocity += (scroll - pan_orbit.zoom_velocity) * smoothing_t;
+
+        if pan_orbit.yaw_velocity.abs() < settings.velocity_threshold {
+            pan_orbit.yaw_velocity = 0.0;
+        }
+        if pan_orbit.pitch_velocity.abs() < settings.velocity_threshold {
+            pan_orbit.pitch_velocity = 0.0;
+        }
+        if pan_orbit.pan_velocity.length_squared() < settings.velocity_threshold * settings.velocity_threshold {
+            pan_orbit.pan_velocity = Vec2::ZERO;
+        }
+        if pan_orbit.zoom_velocity.abs() < settings.velocity_threshold {
+            pan_orbit.zoom_velocity = 0.0;
+        }
+
+        let rotation_move = Vec2::new(pan_orbit.yaw_velocity, pan_orbit.pitch_velocity);
+        let mut pan = pan_orbit.pan_velocity;
+        let scroll = pan_orbit.zoom_velocity;
+
+        if let Some(orbit_button) = settings.orbit_button {
+            if input_mouse.just_pressed(orbit_button) {
+                // anchor the orbit pivot to whatever is under the cursor, so the
+                // thing being looked at stays put while the camera spins around it
+                pan_orbit.orbit_center = picking_camera
+                    .and_then(|picking_camera| picking_camera.intersect_top())
+                    .map(|(_, intersection)| intersection.position());
+            }
+            if input_mouse.just_released(orbit_button) {
+                pan_orbit.orbit_center = None;
+            }
         }
 
         let mut any = false;
         if rotation_move.length_squared() > 0.0 {
             any = true;
             let primary_window_size = Vec2::new(window.width() as f32, window.height() as f32);
-            let delta_x = {
-                let delta = rotation_move.x / primary_window_size.x * std::f32::consts::PI * 2.0;
-                if pan_orbit.upside_down {
-                    -delta
-                } else {
-                    delta
-                }
-            };
-            let delta_y = rotation_move.y / primary_window_size.y * std::f32::consts::PI;
-            let yaw = Quat::from_rotation_y(-delta_x);
-            let pitch = Quat::from_rotation_x(-delta_y);
-            transform.rotation = yaw * transform.rotation; // rotate around global y axis
-            transform.rotation = transform.rotation * pitch; // rotate around local x axis
+            let delta_x = rotation_move.x / primary_window_size.x
+                * std::f32::consts::PI
+                * 2.0
+                * settings.orbit_sensitivity;
+            let delta_y = rotation_move.y / primary_window_size.y
+                * std::f32::consts::PI
+                * settings.orbit_sensitivity;
+
+            pan_orbit.yaw -= delta_x;
+            pan_orbit.pitch -= delta_y;
+            // clamp just short of the poles so the camera can never flip upside down
+            let pitch_limit = std::f32::consts::FRAC_PI_2 - 0.01;
+            pan_orbit.pitch = pan_orbit.pitch.clamp(-pitch_limit, pitch_limit);
 
-        } else if pan.length_squared() > 0.0 {
+            let old_rotation = transform.rotation;
+            transform.rotation = Quat::from_rotation_y(pan_orbit.yaw) * Quat::from_rotation_x(pan_orbit.pitch);
+
+            // orbit about the cached pivot (falling back to focus) rather than
+            // rotating the camera in place, so a cursor-picked point stays anchored
+            let pivot = pan_orbit.orbit_center.unwrap_or(pan_orbit.focus);
+            let rotation_delta = transform.rotation * old_rotation.inverse();
+            transform.translation = pivot + rotation_delta * (transform.translation - pivot);
+            // keep focus consistent with the new translation/rotation/radius
+            pan_orbit.focus =
+                transform.translation - transform.rotation.mul_vec3(Vec3::new(0.0, 0.0, pan_orbit.radius));
+
+        }
+        if pan.length_squared() > 0.0 {
 
             any = true;
             // make panning distance independent of resolution and FOV,
             //let window = get_primary_window_size(&windows);
             let primary_window_size = Vec2::new(window.width() as f32, window.height() as f32);
 
-            if let Projection::Perspective(projection) = projection {
-                pan *= Vec2::new(projection.fov * projection.aspect_ratio, projection.fov) / primary_window_size;
-            }
+            // in perspective, panning speed is scaled by distance from the
+            // focus point (`radius`) since screen-space motion covers more
+            // world space the farther away the camera is; ortho screen-to-world
+            // is distance-independent (that's what `scale` already captures),
+            // so `radius` must not be folded in there too
+            let distance_scale = match &*projection {
+                Projection::Perspective(perspective) => {
+                    pan *= Vec2::new(perspective.fov * perspective.aspect_ratio, perspective.fov)
+                        / primary_window_size;
+                    pan_orbit.radius
+                }
+                Projection::Orthographic(orthographic) => {
+                    pan *= orthographic.scale / primary_window_size;
+                    1.0
+                }
+            };
+            pan *= settings.pan_sensitivity;
             // translate by local axes
             let right = transform.rotation * Vec3::X * -pan.x;
             let up = transform.rotation * Vec3::Y * pan.y;
-            // make panning proportional to distance away from focus point
-            let translation = (right + up) * pan_orbit.radius;
+            let translation = (right + up) * distance_scale;
             pan_orbit.focus += translation;
-        } else if scroll.abs() > 0.0 {
+        }
+        if scroll.abs() > 0.0 {
             any = true;
-            pan_orbit.radius -= scroll * pan_orbit.radius * 0.002;
-            // dont allow zoom to reach zero or you get stuck
-            pan_orbit.radius = f32::max(pan_orbit.radius, 0.05);
+            match &mut *projection {
+                Projection::Perspective(perspective) => {
+                    // perspective zoom dollies via `radius` below; fov is left
+                    // alone here, only kept in bounds in case it drifted elsewhere
+                    perspective.fov = perspective.fov.clamp(settings.min_fov, settings.max_fov);
+                    pan_orbit.radius -= scroll * pan_orbit.radius * settings.zoom_sensitivity;
+                    // dont allow zoom to reach zero or you get stuck
+                    pan_orbit.radius = f32::max(pan_orbit.radius, 0.05);
+                }
+                Projection::Orthographic(orthographic) => {
+                    // ortho zoom is entirely `scale`; `radius` is left untouched
+                    // so it keeps doubling as the pan/orbit-pivot distance
+                    // without dollying the camera through the geometry
+                    orthographic.scale -= scroll * orthographic.scale * settings.zoom_sensitivity;
+                    orthographic.scale = orthographic.scale.clamp(settings.min_scale, settings.max_scale);
+                }
+            }
         }
 
         if any {